@@ -0,0 +1,176 @@
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+use super::view::{View, MAX_INLINE_SIZE};
+use super::{BinaryViewArray, Utf8ViewArray};
+
+/// Once a variadic data buffer reaches this size, new (non-inlined) values are appended to a
+/// fresh buffer instead, bounding how much a single buffer can grow.
+const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
+
+/// A builder of [`BinaryViewArray`]: values up to [`MAX_INLINE_SIZE`] bytes are inlined directly
+/// into their [`View`]; longer values are appended to the current variadic data buffer, which
+/// rolls over to a new one once it reaches `block_size`.
+#[derive(Debug, Clone)]
+pub struct MutableBinaryViewArray {
+    views: Vec<u128>,
+    buffers: Vec<Vec<u8>>,
+    validity: Option<MutableBitmap>,
+    block_size: usize,
+}
+
+impl Default for MutableBinaryViewArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MutableBinaryViewArray {
+    /// Creates a new, empty [`MutableBinaryViewArray`].
+    pub fn new() -> Self {
+        Self {
+            views: Vec::new(),
+            buffers: Vec::new(),
+            validity: None,
+            block_size: DEFAULT_BLOCK_SIZE,
+        }
+    }
+
+    /// Like [`Self::new`], but rolls over to a new data buffer every `block_size` bytes.
+    pub fn with_block_size(block_size: usize) -> Self {
+        Self {
+            block_size,
+            ..Self::new()
+        }
+    }
+
+    /// Appends a value.
+    pub fn push_value(&mut self, value: &[u8]) {
+        let view = if value.len() <= MAX_INLINE_SIZE {
+            View::new_inline(value)
+        } else {
+            if self.buffers.last().map_or(true, |b| b.len() >= self.block_size) {
+                self.buffers.push(Vec::with_capacity(self.block_size.max(value.len())));
+            }
+            let buffer = self.buffers.last_mut().unwrap();
+            let offset = buffer.len();
+            buffer.extend_from_slice(value);
+            View::new_noninline(value, (self.buffers.len() - 1) as u32, offset as u32)
+        };
+        self.views.push(view.0);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true);
+        }
+    }
+
+    /// Appends a null.
+    pub fn push_null(&mut self) {
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => {
+                // every value pushed so far was non-null; backfill the validity bitmap before
+                // recording this one.
+                let mut validity = MutableBitmap::new();
+                validity.extend_constant(self.views.len(), true);
+                validity.push(false);
+                self.validity = Some(validity);
+            }
+        }
+        self.views.push(View::default().0);
+    }
+
+    /// Appends an optional value.
+    pub fn push<V: AsRef<[u8]>>(&mut self, value: Option<V>) {
+        match value {
+            Some(value) => self.push_value(value.as_ref()),
+            None => self.push_null(),
+        }
+    }
+
+    /// The number of elements pushed so far.
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Whether no elements have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Converts this builder into an immutable [`BinaryViewArray`].
+    pub fn into_arc(self) -> std::sync::Arc<dyn crate::array::Array> {
+        std::sync::Arc::new(self.into_array())
+    }
+
+    fn into_array(self) -> BinaryViewArray {
+        BinaryViewArray::new(
+            DataType::BinaryView,
+            self.views.into(),
+            self.buffers.into_iter().map(|b| b.into()).collect(),
+            self.validity.map(|x| x.into()),
+        )
+    }
+}
+
+/// A builder of [`Utf8ViewArray`], identical to [`MutableBinaryViewArray`] except that it only
+/// accepts `&str` values, so every pushed value is valid UTF-8.
+#[derive(Debug, Clone, Default)]
+pub struct MutableUtf8ViewArray {
+    inner: MutableBinaryViewArray,
+}
+
+impl MutableUtf8ViewArray {
+    /// Creates a new, empty [`MutableUtf8ViewArray`].
+    pub fn new() -> Self {
+        Self {
+            inner: MutableBinaryViewArray::new(),
+        }
+    }
+
+    /// Appends a value.
+    pub fn push_value(&mut self, value: &str) {
+        self.inner.push_value(value.as_bytes());
+    }
+
+    /// Appends a null.
+    pub fn push_null(&mut self) {
+        self.inner.push_null();
+    }
+
+    /// Appends an optional value.
+    pub fn push<V: AsRef<str>>(&mut self, value: Option<V>) {
+        match value {
+            Some(value) => self.push_value(value.as_ref()),
+            None => self.push_null(),
+        }
+    }
+
+    /// The number of elements pushed so far.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether no elements have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Converts this builder into an immutable [`Utf8ViewArray`].
+    ///
+    /// Every value pushed through [`Self::push_value`]/[`Self::push`] is already known to be
+    /// valid UTF-8 (it came from a `&str`), so this cannot fail.
+    pub fn into_arc(self) -> std::sync::Arc<dyn crate::array::Array> {
+        std::sync::Arc::new(self.into_array().unwrap())
+    }
+
+    fn into_array(self) -> Result<Utf8ViewArray> {
+        let array = self.inner.into_array();
+        Utf8ViewArray::try_new(
+            DataType::Utf8View,
+            array.views().clone(),
+            array.data_buffers().to_vec(),
+            array.validity().cloned(),
+        )
+    }
+}
@@ -0,0 +1,103 @@
+/// Values up to this many bytes are inlined directly into a [`View`]; longer values are stored
+/// in a variadic data buffer and referenced by a prefix + buffer index + offset instead.
+pub const MAX_INLINE_SIZE: usize = 12;
+
+/// A single 16-byte view into a [`super::BinaryViewArray`]/[`super::Utf8ViewArray`] element.
+///
+/// Layout (little-endian):
+/// * bytes `0..4`: the value's length, as a `u32`.
+/// * if `length <= 12`: bytes `4..16` hold the value, inlined.
+/// * otherwise: bytes `4..8` are the value's first 4 bytes (a prefix, used to short-circuit
+///   comparisons), bytes `8..12` index into the array's variadic data buffers, and bytes
+///   `12..16` are the byte offset of the value within that buffer.
+///
+/// Two views can be `==` and compare their 16 bytes directly, without dereferencing the data
+/// buffers, which is what makes slicing and per-element cloning of these arrays `O(1)`: a view
+/// is `Copy` and carries everything needed to resolve a value later.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct View(pub u128);
+
+impl View {
+    /// The length, in bytes, of the value this view points to.
+    #[inline]
+    pub fn length(&self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Whether the value is inlined in this view (no data buffer lookup required).
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.length() as usize <= MAX_INLINE_SIZE
+    }
+
+    /// Builds a view for a `value` of at most [`MAX_INLINE_SIZE`] bytes, inlining it.
+    pub fn new_inline(value: &[u8]) -> Self {
+        debug_assert!(value.len() <= MAX_INLINE_SIZE);
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&(value.len() as u32).to_ne_bytes());
+        bytes[4..4 + value.len()].copy_from_slice(value);
+        Self(u128::from_ne_bytes(bytes))
+    }
+
+    /// Builds a view for a `value` longer than [`MAX_INLINE_SIZE`] bytes, which lives at
+    /// `offset` in the data buffer indexed by `buffer_idx`.
+    pub fn new_noninline(value: &[u8], buffer_idx: u32, offset: u32) -> Self {
+        debug_assert!(value.len() > MAX_INLINE_SIZE);
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&(value.len() as u32).to_ne_bytes());
+        bytes[4..8].copy_from_slice(&value[0..4]);
+        bytes[8..12].copy_from_slice(&buffer_idx.to_ne_bytes());
+        bytes[12..16].copy_from_slice(&offset.to_ne_bytes());
+        Self(u128::from_ne_bytes(bytes))
+    }
+
+    /// A view onto the `length()` inlined bytes, valid only while `self` (or its backing
+    /// buffer) is alive. Only meaningful when [`Self::is_inline`] is `true`.
+    ///
+    /// # Safety
+    /// `self` must outlive the returned slice; since [`View`] is `Copy`, callers that hold a
+    /// local `View` rather than a reference into the backing `Buffer<u128>` must not let this
+    /// slice escape that local's lifetime.
+    #[inline]
+    pub unsafe fn inlined(&self) -> &[u8] {
+        let ptr = (self as *const Self as *const u8).add(4);
+        std::slice::from_raw_parts(ptr, self.length() as usize)
+    }
+
+    /// The 4-byte prefix of a non-inlined value.
+    #[inline]
+    pub fn prefix(&self) -> [u8; 4] {
+        self.as_ne_bytes()[4..8].try_into().unwrap()
+    }
+
+    /// The index, into the array's variadic data buffers, of a non-inlined value.
+    #[inline]
+    pub fn buffer_idx(&self) -> u32 {
+        u32::from_ne_bytes(self.as_ne_bytes()[8..12].try_into().unwrap())
+    }
+
+    /// The byte offset of a non-inlined value within its data buffer.
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        u32::from_ne_bytes(self.as_ne_bytes()[12..16].try_into().unwrap())
+    }
+
+    fn as_ne_bytes(&self) -> [u8; 16] {
+        self.0.to_ne_bytes()
+    }
+}
+
+impl From<View> for u128 {
+    #[inline]
+    fn from(view: View) -> u128 {
+        view.0
+    }
+}
+
+impl From<u128> for View {
+    #[inline]
+    fn from(value: u128) -> View {
+        View(value)
+    }
+}
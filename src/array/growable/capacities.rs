@@ -0,0 +1,119 @@
+use crate::array::{Array, FixedSizeListArray, ListArray, StructArray};
+use crate::datatypes::DataType;
+use crate::types::Offset;
+
+/// A hint of how many elements a [`super::Growable`] will end up holding, so that its backing
+/// buffers can be allocated once up front instead of reallocating as elements are appended.
+///
+/// Nested types carry a capacity per level: [`Capacities::List`] gives the capacity of the list
+/// itself and, optionally, the capacity of its child (which may in turn be a
+/// [`Capacities::List`] for doubly-nested types). A missing child hint (`None`) falls back to
+/// the default (reallocating) behavior for that child. `Struct`'s fields share their parent's
+/// row count but each has its own (possibly further-nested) type, so [`Capacities::Struct`]
+/// carries one child hint per field.
+#[derive(Debug, Clone)]
+pub enum Capacities {
+    /// The capacity of a flat (non-nested) array.
+    Array(usize),
+    /// The capacity of a list-like array (`List`, `LargeList`, `FixedSizeList`), and,
+    /// optionally, a hint for its child.
+    List(usize, Option<Box<Capacities>>),
+    /// The capacity of a `Struct` array (its row count), and a hint for each of its fields, in
+    /// field order.
+    Struct(usize, Vec<Capacities>),
+}
+
+impl Capacities {
+    /// The capacity at this level (ignoring any nested hint).
+    pub fn len(&self) -> usize {
+        match self {
+            Capacities::Array(len) => *len,
+            Capacities::List(len, _) => *len,
+            Capacities::Struct(len, _) => *len,
+        }
+    }
+
+    /// Whether this level's capacity is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for Capacities {
+    fn default() -> Self {
+        Capacities::Array(0)
+    }
+}
+
+fn list_values<O: Offset>(arrays: &[&dyn Array]) -> Vec<&dyn Array> {
+    arrays
+        .iter()
+        .map(|array| {
+            array
+                .as_any()
+                .downcast_ref::<ListArray<O>>()
+                .unwrap()
+                .values()
+                .as_ref()
+        })
+        .collect()
+}
+
+/// Computes the [`Capacities`] that concatenating `arrays` will require, by walking each array's
+/// [`DataType`] and summing the lengths of its (possibly further-nested) children across all
+/// inputs.
+///
+/// This lets `concatenate` give every [`super::Growable`] an exact up-front size, so each leaf
+/// builder performs a single allocation instead of growing repeatedly as rows are appended.
+pub fn capacities(arrays: &[&dyn Array]) -> Capacities {
+    let total_len = arrays.iter().map(|x| x.len()).sum();
+
+    let Some(first) = arrays.first() else {
+        return Capacities::Array(0);
+    };
+
+    match first.data_type() {
+        DataType::FixedSizeList(_, _) => {
+            let children = arrays
+                .iter()
+                .map(|array| {
+                    array
+                        .as_any()
+                        .downcast_ref::<FixedSizeListArray>()
+                        .unwrap()
+                        .values()
+                        .as_ref()
+                })
+                .collect::<Vec<_>>();
+            Capacities::List(total_len, Some(Box::new(capacities(&children))))
+        }
+        DataType::List(_) => {
+            let children = list_values::<i32>(arrays);
+            Capacities::List(total_len, Some(Box::new(capacities(&children))))
+        }
+        DataType::LargeList(_) => {
+            let children = list_values::<i64>(arrays);
+            Capacities::List(total_len, Some(Box::new(capacities(&children))))
+        }
+        DataType::Struct(fields) => {
+            let children = (0..fields.len())
+                .map(|field_index| {
+                    let field_arrays = arrays
+                        .iter()
+                        .map(|array| {
+                            array
+                                .as_any()
+                                .downcast_ref::<StructArray>()
+                                .unwrap()
+                                .values()[field_index]
+                                .as_ref()
+                        })
+                        .collect::<Vec<_>>();
+                    capacities(&field_arrays)
+                })
+                .collect();
+            Capacities::Struct(total_len, children)
+        }
+        _ => Capacities::Array(total_len),
+    }
+}
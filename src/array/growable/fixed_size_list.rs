@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::array::{Array, FixedSizeListArray};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+use super::{make_growable, Capacities, Growable};
+
+/// A [`Growable`] for [`FixedSizeListArray`]: its child values share a single inner [`Growable`]
+/// (built once, via [`make_growable`], from `capacity`'s child hint) across every
+/// [`Growable::extend`], so the whole nested array allocates its buffers up front instead of one
+/// list at a time.
+pub struct GrowableFixedSizeList<'a> {
+    arrays: Vec<&'a FixedSizeListArray>,
+    data_type: DataType,
+    size: usize,
+    values: Box<dyn Growable<'a> + 'a>,
+    validity: Option<MutableBitmap>,
+    len: usize,
+}
+
+impl<'a> GrowableFixedSizeList<'a> {
+    /// Creates a new [`GrowableFixedSizeList`] over `arrays`, pre-allocating according to
+    /// `capacity` (and its child hint, for the values array).
+    pub fn new(arrays: Vec<&'a FixedSizeListArray>, capacity: Capacities) -> Result<Self> {
+        let data_type = arrays[0].data_type().clone();
+        let size = match &data_type {
+            DataType::FixedSizeList(_, size) => *size,
+            _ => unreachable!("GrowableFixedSizeList requires a FixedSizeList array"),
+        };
+        let child_capacity = match capacity {
+            Capacities::List(_, Some(child)) => *child,
+            _ => Capacities::default(),
+        };
+        let child_arrays = arrays.iter().map(|array| array.values().as_ref()).collect::<Vec<_>>();
+        let values = make_growable(&child_arrays, child_capacity)?;
+        Ok(Self {
+            arrays,
+            data_type,
+            size,
+            values,
+            validity: None,
+            len: 0,
+        })
+    }
+
+    /// Records whether the row just extended (or about to be pushed null) is valid, lazily
+    /// allocating the validity bitmap the first time an invalid row is seen (every row before it
+    /// was implicitly valid).
+    fn push_validity(&mut self, valid: bool) {
+        match &mut self.validity {
+            Some(validity) => validity.push(valid),
+            None if !valid => {
+                let mut validity = MutableBitmap::new();
+                validity.extend_constant(self.len, true);
+                validity.push(false);
+                self.validity = Some(validity);
+            }
+            None => {}
+        }
+        self.len += 1;
+    }
+}
+
+impl<'a> Growable<'a> for GrowableFixedSizeList<'a> {
+    fn extend(&mut self, index: usize, offset: usize, len: usize) {
+        let array = self.arrays[index];
+        self.values.extend(index, offset * self.size, len * self.size);
+        match array.validity() {
+            Some(validity) => {
+                for i in offset..offset + len {
+                    self.push_validity(validity.get_bit(i));
+                }
+            }
+            None => {
+                for _ in 0..len {
+                    self.push_validity(true);
+                }
+            }
+        }
+    }
+
+    fn extend_null(&mut self) {
+        for _ in 0..self.size {
+            self.values.extend_null();
+        }
+        self.push_validity(false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        let values = self.values.as_arc();
+        let validity = self.validity.take().map(Into::into);
+        Arc::new(FixedSizeListArray::new(self.data_type.clone(), values, validity))
+    }
+}
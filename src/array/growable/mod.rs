@@ -0,0 +1,76 @@
+//! Contains the [`Growable`] trait, used to build a new array by copying ranges out of one or
+//! more existing arrays, and the [`Capacities`] hint that lets it preallocate its backing
+//! buffers instead of reallocating on every [`Growable::extend`].
+mod capacities;
+mod fixed_size_list;
+mod primitive;
+mod structure;
+
+pub use capacities::{capacities, Capacities};
+pub use fixed_size_list::GrowableFixedSizeList;
+pub use primitive::GrowablePrimitive;
+pub use structure::GrowableStruct;
+
+use std::sync::Arc;
+
+use crate::array::{Array, FixedSizeListArray, PrimitiveArray, StructArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Incrementally builds a new array of a single concrete type by copying ranges of rows out of
+/// one or more same-typed input arrays.
+pub trait Growable<'a> {
+    /// Extends this `Growable` with `len` rows starting at `offset` from the input array at
+    /// `index` (as originally given to the `Growable`'s constructor).
+    fn extend(&mut self, index: usize, offset: usize, len: usize);
+
+    /// Extends this `Growable` with a single null row.
+    fn extend_null(&mut self);
+
+    /// Finishes building, returning the resulting array.
+    fn as_arc(&mut self) -> Arc<dyn Array>;
+}
+
+fn downcast_primitive<'a, T: NativeType>(arrays: &[&'a dyn Array]) -> Vec<&'a PrimitiveArray<T>> {
+    arrays
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap())
+        .collect()
+}
+
+/// Builds the [`Growable`] appropriate for `arrays`' (shared) [`DataType`], pre-allocated
+/// according to `capacity` (as computed by [`capacities`]).
+///
+/// Supports the same flat numeric types [`crate::compute::cast::cast`] does, plus `FixedSizeList`
+/// and `Struct`, each of which recurses into its child/field `Growable`s so that every level of a
+/// nested array gets its own single up-front allocation rather than just the top one. Any other
+/// type is [`ArrowError::nyi`] rather than silently falling back to an unsized,
+/// repeatedly-reallocating builder.
+pub fn make_growable<'a>(arrays: &[&'a dyn Array], capacity: Capacities) -> Result<Box<dyn Growable<'a> + 'a>> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::oos("make_growable requires at least one array"));
+    };
+
+    Ok(match first.data_type() {
+        DataType::Int32 => Box::new(GrowablePrimitive::<i32>::new(downcast_primitive(arrays), capacity)),
+        DataType::Int64 => Box::new(GrowablePrimitive::<i64>::new(downcast_primitive(arrays), capacity)),
+        DataType::Float32 => Box::new(GrowablePrimitive::<f32>::new(downcast_primitive(arrays), capacity)),
+        DataType::Float64 => Box::new(GrowablePrimitive::<f64>::new(downcast_primitive(arrays), capacity)),
+        DataType::FixedSizeList(_, _) => {
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref::<FixedSizeListArray>().unwrap())
+                .collect();
+            Box::new(fixed_size_list::GrowableFixedSizeList::new(arrays, capacity)?)
+        }
+        DataType::Struct(_) => {
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref::<StructArray>().unwrap())
+                .collect();
+            Box::new(structure::GrowableStruct::new(arrays, capacity)?)
+        }
+        other => return Err(ArrowError::nyi(format!("make_growable does not support {other:?} yet"))),
+    })
+}
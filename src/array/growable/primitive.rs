@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::array::{Array, MutablePrimitiveArray, PrimitiveArray};
+use crate::types::NativeType;
+
+use super::{Capacities, Growable};
+
+/// A [`Growable`] for [`PrimitiveArray`], the first (and simplest) concrete consumer of
+/// [`Capacities`]: `capacity.len()` is passed straight to [`MutablePrimitiveArray::with_capacity`]
+/// so the backing buffer is allocated once, rather than growing on every [`Growable::extend`].
+pub struct GrowablePrimitive<'a, T: NativeType> {
+    arrays: Vec<&'a PrimitiveArray<T>>,
+    values: MutablePrimitiveArray<T>,
+}
+
+impl<'a, T: NativeType> GrowablePrimitive<'a, T> {
+    /// Creates a new [`GrowablePrimitive`] over `arrays`, pre-allocating according to `capacity`.
+    pub fn new(arrays: Vec<&'a PrimitiveArray<T>>, capacity: Capacities) -> Self {
+        Self {
+            arrays,
+            values: MutablePrimitiveArray::<T>::with_capacity(capacity.len()),
+        }
+    }
+}
+
+impl<'a, T: NativeType> Growable<'a> for GrowablePrimitive<'a, T> {
+    fn extend(&mut self, index: usize, offset: usize, len: usize) {
+        let array = self.arrays[index];
+        match array.validity() {
+            Some(validity) => {
+                for i in offset..offset + len {
+                    let value = validity.get_bit(i).then(|| array.value(i));
+                    self.values.push(value);
+                }
+            }
+            None => {
+                for i in offset..offset + len {
+                    self.values.push(Some(array.value(i)));
+                }
+            }
+        }
+    }
+
+    fn extend_null(&mut self) {
+        self.values.push(None);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        std::mem::take(&mut self.values).into_arc()
+    }
+}
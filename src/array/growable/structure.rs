@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::array::{Array, StructArray};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+use super::{make_growable, Capacities, Growable};
+
+/// A [`Growable`] for [`StructArray`]: each field gets its own inner [`Growable`] (built once,
+/// via [`make_growable`], from `capacity`'s per-field hints), so every field's buffers allocate
+/// up front instead of growing one row at a time.
+pub struct GrowableStruct<'a> {
+    arrays: Vec<&'a StructArray>,
+    data_type: DataType,
+    values: Vec<Box<dyn Growable<'a> + 'a>>,
+    validity: Option<MutableBitmap>,
+    len: usize,
+}
+
+impl<'a> GrowableStruct<'a> {
+    /// Creates a new [`GrowableStruct`] over `arrays`, pre-allocating each field according to
+    /// `capacity`'s per-field hints.
+    pub fn new(arrays: Vec<&'a StructArray>, capacity: Capacities) -> Result<Self> {
+        let data_type = arrays[0].data_type().clone();
+        let field_count = match &data_type {
+            DataType::Struct(fields) => fields.len(),
+            _ => unreachable!("GrowableStruct requires a Struct array"),
+        };
+        let child_capacities = match capacity {
+            Capacities::Struct(_, children) if children.len() == field_count => children,
+            _ => (0..field_count).map(|_| Capacities::default()).collect(),
+        };
+        let values = (0..field_count)
+            .zip(child_capacities)
+            .map(|(field_index, child_capacity)| {
+                let field_arrays = arrays
+                    .iter()
+                    .map(|array| array.values()[field_index].as_ref())
+                    .collect::<Vec<_>>();
+                make_growable(&field_arrays, child_capacity)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            arrays,
+            data_type,
+            values,
+            validity: None,
+            len: 0,
+        })
+    }
+
+    /// Records whether the row just extended (or about to be pushed null) is valid, lazily
+    /// allocating the validity bitmap the first time an invalid row is seen (every row before it
+    /// was implicitly valid).
+    fn push_validity(&mut self, valid: bool) {
+        match &mut self.validity {
+            Some(validity) => validity.push(valid),
+            None if !valid => {
+                let mut validity = MutableBitmap::new();
+                validity.extend_constant(self.len, true);
+                validity.push(false);
+                self.validity = Some(validity);
+            }
+            None => {}
+        }
+        self.len += 1;
+    }
+}
+
+impl<'a> Growable<'a> for GrowableStruct<'a> {
+    fn extend(&mut self, index: usize, offset: usize, len: usize) {
+        let array = self.arrays[index];
+        for field in &mut self.values {
+            field.extend(index, offset, len);
+        }
+        match array.validity() {
+            Some(validity) => {
+                for i in offset..offset + len {
+                    self.push_validity(validity.get_bit(i));
+                }
+            }
+            None => {
+                for _ in 0..len {
+                    self.push_validity(true);
+                }
+            }
+        }
+    }
+
+    fn extend_null(&mut self) {
+        for field in &mut self.values {
+            field.extend_null();
+        }
+        self.push_validity(false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        let values = self.values.iter_mut().map(|field| field.as_arc()).collect();
+        let validity = self.validity.take().map(Into::into);
+        Arc::new(StructArray::new(self.data_type.clone(), values, validity))
+    }
+}
@@ -0,0 +1,45 @@
+//! Kernels to cast arrays between [`crate::datatypes::DataType`]s.
+mod structural_rename;
+
+pub use structural_rename::{cast_rename, is_rename_only};
+
+use crate::array::{Array, Int64Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Casts `array` to `to_type`.
+///
+/// A cast whose only difference from `array`'s type is child field names (e.g. renaming a
+/// `List`'s item field, or a `Struct`'s fields) is routed through [`cast_rename`], which reuses
+/// the existing buffers unchanged; every other cast goes through normal, value-converting
+/// casting.
+pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
+    let from_type = array.data_type();
+    if from_type == to_type {
+        return Ok(array.to_boxed());
+    }
+    if is_rename_only(from_type, to_type) {
+        return cast_rename(array, to_type);
+    }
+
+    match (from_type, to_type) {
+        (DataType::Int32, DataType::Int64) => {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+            let values = array.iter().map(|v| v.map(|v| *v as i64)).collect::<Vec<_>>();
+            Ok(Box::new(Int64Array::from(values)))
+        }
+        (DataType::Int32, DataType::Float64) => {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+            let values = array.iter().map(|v| v.map(|v| *v as f64)).collect::<Vec<_>>();
+            Ok(Box::new(PrimitiveArray::<f64>::from(values)))
+        }
+        (DataType::Float32, DataType::Float64) => {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<f32>>().unwrap();
+            let values = array.iter().map(|v| v.map(|v| *v as f64)).collect::<Vec<_>>();
+            Ok(Box::new(PrimitiveArray::<f64>::from(values)))
+        }
+        _ => Err(ArrowError::nyi(format!(
+            "casting {from_type:?} to {to_type:?} is not supported"
+        ))),
+    }
+}
@@ -0,0 +1,108 @@
+use crate::array::{Array, ListArray, MapArray, StructArray};
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+/// Whether `from` can be cast to `to` by only renaming child fields: a `Map`'s entries/key/value
+/// names, a `List`'s item name, or a `Struct`'s field names, with every child *type* and
+/// *nullability* identical.
+///
+/// Casts that also differ in a child's type or nullability are not a rename and must go through
+/// the normal recursive `cast` kernel instead.
+pub fn is_rename_only(from: &DataType, to: &DataType) -> bool {
+    if from == to {
+        return false;
+    }
+    match (from, to) {
+        (DataType::Map(from_field, from_sorted), DataType::Map(to_field, to_sorted)) => {
+            from_sorted == to_sorted && is_field_rename_compatible(from_field, to_field)
+        }
+        (DataType::List(from_field), DataType::List(to_field))
+        | (DataType::LargeList(from_field), DataType::LargeList(to_field)) => {
+            is_field_rename_compatible(from_field, to_field)
+        }
+        (DataType::Struct(from_fields), DataType::Struct(to_fields)) => {
+            from_fields.len() == to_fields.len()
+                && from_fields
+                    .iter()
+                    .zip(to_fields.iter())
+                    .all(|(f, t)| is_field_rename_compatible(f, t))
+        }
+        _ => false,
+    }
+}
+
+/// Whether `from` and `to` describe the same child slot up to a name change: same nullability,
+/// and either an identical type or one that is itself rename-only.
+///
+/// Nullability must match exactly: reusing a buffer whose validity was built for one
+/// nullability under a `Field` claiming the other would let a "non-nullable" field read as
+/// having nulls (or silently assert non-null incorrectly), which a rename must never do.
+fn is_field_rename_compatible(from: &Field, to: &Field) -> bool {
+    from.is_nullable == to.is_nullable && is_rename_or_equal(&from.data_type, &to.data_type)
+}
+
+fn is_rename_or_equal(from: &DataType, to: &DataType) -> bool {
+    from == to || is_rename_only(from, to)
+}
+
+/// Casts `array` to `to`, reusing the underlying buffers unchanged and only rebuilding the
+/// `DataType`/child `Field`s to carry `to`'s names.
+///
+/// # Errors
+/// Errors if a non-nested child's type differs from the corresponding type in `to`.
+///
+/// # Panics
+/// Panics (via downcast) if `array` (or one of its nested children) is not the array type of the
+/// corresponding nested `to` type, e.g. `to` is a `List` but `array` is not a `ListArray<i32>`.
+pub fn cast_rename(array: &dyn Array, to: &DataType) -> Result<Box<dyn Array>> {
+    match to {
+        DataType::Map(to_field, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let entries = cast_rename(array.field().as_ref(), &to_field.data_type)?;
+            Ok(Box::new(MapArray::new(
+                to.clone(),
+                array.offsets().clone(),
+                entries.into(),
+                array.validity().cloned(),
+            )))
+        }
+        DataType::List(to_field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = cast_rename(array.values().as_ref(), &to_field.data_type)?;
+            Ok(Box::new(ListArray::<i32>::new(
+                to.clone(),
+                array.offsets().clone(),
+                values.into(),
+                array.validity().cloned(),
+            )))
+        }
+        DataType::LargeList(to_field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = cast_rename(array.values().as_ref(), &to_field.data_type)?;
+            Ok(Box::new(ListArray::<i64>::new(
+                to.clone(),
+                array.offsets().clone(),
+                values.into(),
+                array.validity().cloned(),
+            )))
+        }
+        DataType::Struct(to_fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .zip(to_fields.iter())
+                .map(|(column, to_field)| cast_rename(column.as_ref(), &to_field.data_type).map(Into::into))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Box::new(StructArray::new(
+                to.clone(),
+                values,
+                array.validity().cloned(),
+            )))
+        }
+        _ if to == array.data_type() => Ok(array.to_boxed()),
+        _ => Err(ArrowError::nyi(
+            "cast_rename called with datatypes that are not rename-compatible",
+        )),
+    }
+}
@@ -0,0 +1,26 @@
+//! Concatenates arrays of the same [`DataType`](crate::datatypes::DataType) into one.
+use std::sync::Arc;
+
+use crate::array::growable::{capacities, make_growable};
+use crate::array::Array;
+use crate::error::{ArrowError, Result};
+
+/// Concatenates `arrays` (which must all share the same [`DataType`](crate::datatypes::DataType))
+/// into a single array.
+///
+/// Computes the combined [`Capacities`](crate::array::growable::Capacities) hint up front via
+/// [`capacities`] and builds the result through a single [`Growable`](crate::array::growable::Growable),
+/// so (for the types `make_growable` supports) every level of a nested array allocates its
+/// buffers once rather than growing one input at a time.
+pub fn concatenate(arrays: &[&dyn Array]) -> Result<Arc<dyn Array>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::oos("concatenate requires at least one array"));
+    }
+
+    let capacity = capacities(arrays);
+    let mut growable = make_growable(arrays, capacity)?;
+    for (index, array) in arrays.iter().enumerate() {
+        growable.extend(index, 0, array.len());
+    }
+    Ok(growable.as_arc())
+}
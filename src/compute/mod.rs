@@ -0,0 +1,3 @@
+//! Arithmetic, comparison, boolean, and other kernels that operate on [`crate::array::Array`]s.
+pub mod cast;
+pub mod concatenate;
@@ -0,0 +1,3 @@
+//! APIs to read from and write to Apache Avro format.
+pub mod read;
+pub mod write;
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::{ArrowError, Result};
+use crate::types::months_days_ns;
+
+use super::schema::{AvroField, PhysicalType};
+
+/// A cursor over a single (already decompressed) Avro block, used to decode one record at a
+/// time in field order.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn read_zigzag(&mut self) -> Result<i64> {
+        let mut value: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = *self
+                .bytes
+                .get(self.offset)
+                .ok_or_else(|| ArrowError::oos("unexpected end of Avro block"))?;
+            self.offset += 1;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.read_zigzag()? as usize;
+        let slice = self
+            .bytes
+            .get(self.offset..self.offset + len)
+            .ok_or_else(|| ArrowError::oos("unexpected end of Avro block"))?;
+        self.offset += len;
+        Ok(slice)
+    }
+
+    fn read_fixed(&mut self, size: usize) -> Result<&'a [u8]> {
+        let slice = self
+            .bytes
+            .get(self.offset..self.offset + size)
+            .ok_or_else(|| ArrowError::oos("unexpected end of Avro block"))?;
+        self.offset += size;
+        Ok(slice)
+    }
+
+    fn read_bool(&mut self) -> Result<bool> {
+        let byte = *self
+            .bytes
+            .get(self.offset)
+            .ok_or_else(|| ArrowError::oos("unexpected end of Avro block"))?;
+        self.offset += 1;
+        Ok(byte != 0)
+    }
+
+    fn read_f32(&mut self) -> Result<f32> {
+        let slice = self.read_fixed(4)?;
+        Ok(f32::from_le_bytes(slice.try_into().unwrap()))
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        let slice = self.read_fixed(8)?;
+        Ok(f64::from_le_bytes(slice.try_into().unwrap()))
+    }
+}
+
+/// Decodes a big-endian two's-complement integer of arbitrary byte length into an `i128`.
+fn decode_decimal_bytes(bytes: &[u8]) -> i128 {
+    let negative = bytes.first().map_or(false, |b| b & 0x80 != 0);
+    let mut value: i128 = if negative { -1 } else { 0 };
+    for &byte in bytes {
+        value = (value << 8) | byte as i128;
+    }
+    value
+}
+
+/// Decodes a `fixed(12)` Avro `duration` (little-endian months, days, milliseconds) into arrow's
+/// `months_days_ns` interval representation.
+fn decode_duration(bytes: &[u8]) -> months_days_ns {
+    let months = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as i32;
+    let days = u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as i32;
+    let millis = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as i64;
+    months_days_ns::new(months, days, millis * 1_000_000)
+}
+
+/// Decodes one row's worth of `avro_field` (honoring `nullable` via its leading union branch)
+/// into `array`.
+fn deserialize_item(
+    cursor: &mut Cursor,
+    avro_field: &AvroField,
+    array: &mut Box<dyn MutableArray>,
+) -> Result<()> {
+    if avro_field.nullable {
+        let branch = cursor.read_zigzag()?;
+        if branch == 0 {
+            array.push_null();
+            return Ok(());
+        }
+    }
+
+    match &avro_field.physical_type {
+        PhysicalType::Null => array.push_null(),
+        PhysicalType::Boolean => {
+            let value = cursor.read_bool()?;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutableBooleanArray>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Int | PhysicalType::Date32 | PhysicalType::TimeMillis => {
+            let value = cursor.read_zigzag()? as i32;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutablePrimitiveArray<i32>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Long
+        | PhysicalType::TimeMicros
+        | PhysicalType::TimestampMillis
+        | PhysicalType::TimestampMicros => {
+            let value = cursor.read_zigzag()?;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutablePrimitiveArray<i64>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Float => {
+            let value = cursor.read_f32()?;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutablePrimitiveArray<f32>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Double => {
+            let value = cursor.read_f64()?;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutablePrimitiveArray<f64>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Bytes => {
+            let value = cursor.read_bytes()?;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutableBinaryArray<i32>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::String => {
+            let value = cursor.read_bytes()?;
+            let value = simdutf8::basic::from_utf8(value)?;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutableUtf8Array<i32>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Decimal { size, .. } => {
+            let bytes = match size {
+                Some(size) => cursor.read_fixed(*size)?,
+                None => cursor.read_bytes()?,
+            };
+            let value = decode_decimal_bytes(bytes);
+            array
+                .as_mut_any()
+                .downcast_mut::<MutablePrimitiveArray<i128>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Duration => {
+            let bytes = cursor.read_fixed(12)?;
+            let value = decode_duration(bytes);
+            array
+                .as_mut_any()
+                .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+                .unwrap()
+                .push(Some(value));
+        }
+        PhysicalType::Enum(_) => {
+            let index = cursor.read_zigzag()? as i32;
+            array
+                .as_mut_any()
+                .downcast_mut::<MutableDictionaryArray<i32, MutableUtf8Array<i32>>>()
+                .unwrap()
+                .try_push_valid(index)?;
+        }
+        PhysicalType::List(item) => {
+            let list = array
+                .as_mut_any()
+                .downcast_mut::<MutableListArray<i32, Box<dyn MutableArray>>>()
+                .unwrap();
+            loop {
+                let count = cursor.read_zigzag()?;
+                if count == 0 {
+                    break;
+                }
+                // a negative block count is followed by its byte size, which we do not need; the
+                // sign must be read off `count` before it is made positive below.
+                let negative = count < 0;
+                let count = count.unsigned_abs();
+                if negative {
+                    cursor.read_zigzag()?;
+                }
+                for _ in 0..count {
+                    deserialize_item(cursor, item, list.mut_values())?;
+                }
+            }
+            list.try_push_valid()?;
+        }
+    }
+    Ok(())
+}
+
+fn new_builder(avro_field: &AvroField, field: &Field) -> Box<dyn MutableArray> {
+    match &avro_field.physical_type {
+        PhysicalType::Null => Box::new(MutableBooleanArray::new()),
+        PhysicalType::Boolean => Box::new(MutableBooleanArray::new()),
+        PhysicalType::Int | PhysicalType::Date32 | PhysicalType::TimeMillis => {
+            Box::new(MutablePrimitiveArray::<i32>::new().to(field.data_type.clone()))
+        }
+        PhysicalType::Long
+        | PhysicalType::TimeMicros
+        | PhysicalType::TimestampMillis
+        | PhysicalType::TimestampMicros => {
+            Box::new(MutablePrimitiveArray::<i64>::new().to(field.data_type.clone()))
+        }
+        PhysicalType::Float => Box::new(MutablePrimitiveArray::<f32>::new()),
+        PhysicalType::Double => Box::new(MutablePrimitiveArray::<f64>::new()),
+        PhysicalType::Bytes => Box::new(MutableBinaryArray::<i32>::new()),
+        PhysicalType::String => Box::new(MutableUtf8Array::<i32>::new()),
+        PhysicalType::Decimal { .. } => {
+            Box::new(MutablePrimitiveArray::<i128>::new().to(field.data_type.clone()))
+        }
+        PhysicalType::Duration => Box::new(MutablePrimitiveArray::<months_days_ns>::new()),
+        PhysicalType::Enum(symbols) => {
+            let mut values = MutableUtf8Array::<i32>::new();
+            values.extend_values(symbols.iter());
+            Box::new(
+                MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::try_empty(values).unwrap(),
+            )
+        }
+        PhysicalType::List(item) => {
+            let item_field = match &field.data_type {
+                crate::datatypes::DataType::List(inner) => inner.as_ref(),
+                _ => unreachable!(),
+            };
+            let values = new_builder(item, item_field);
+            Box::new(MutableListArray::<i32, Box<dyn MutableArray>>::new_from(
+                values,
+                field.data_type.clone(),
+                0,
+            ))
+        }
+    }
+}
+
+/// Decodes a single (already decompressed) Avro block containing `rows` records into a
+/// `Chunk`, following the decode plan in `avro_fields` and the target types in `fields`.
+pub fn deserialize(bytes: &[u8], rows: usize, avro_fields: &[AvroField], fields: &[Field]) -> Result<Chunk<Arc<dyn Array>>> {
+    let mut builders = avro_fields
+        .iter()
+        .zip(fields.iter())
+        .map(|(a, f)| new_builder(a, f))
+        .collect::<Vec<_>>();
+
+    let mut cursor = Cursor::new(bytes);
+    for _ in 0..rows {
+        for (avro_field, builder) in avro_fields.iter().zip(builders.iter_mut()) {
+            deserialize_item(&mut cursor, avro_field, builder)?;
+        }
+    }
+
+    let columns = builders
+        .iter_mut()
+        .map(|builder| builder.as_box().into())
+        .collect::<Vec<Arc<dyn Array>>>();
+
+    Chunk::try_new(columns)
+}
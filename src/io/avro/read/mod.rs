@@ -0,0 +1,214 @@
+//! APIs to read from Apache Avro format.
+mod deserialize;
+mod schema;
+
+use std::io::Read;
+use std::sync::Arc;
+
+pub use schema::AvroSchema;
+use schema::AvroField;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+
+const MAGIC: [u8; 4] = [b'O', b'b', b'j', 1];
+
+/// The block compression codecs understood by the reader, mirroring [`super::write::Compression`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Null,
+    Deflate,
+    Snappy,
+}
+
+fn read_zigzag<R: Read>(reader: &mut R) -> Result<i64> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0u8; 1];
+        reader.read_exact(&mut byte)?;
+        value |= ((byte[0] & 0x7F) as u64) << shift;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+}
+
+fn read_avro_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let len = read_zigzag(reader)? as usize;
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads the Avro Object Container File header: the magic bytes, the metadata map (from which
+/// the record schema and codec are extracted), and the 16-byte sync marker.
+///
+/// Returns the parsed Avro schema, the inferred arrow [`Schema`], the block [`Compression`],
+/// and the file's sync marker (needed to iterate its blocks with [`BlockStreamIterator`]).
+pub fn read_metadata<R: Read>(reader: &mut R) -> Result<(AvroSchema, Schema, Compression, [u8; 16])> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(ArrowError::oos("Avro file does not start with the expected magic bytes"));
+    }
+
+    let mut avro_schema = None;
+    let mut codec = Compression::Null;
+    loop {
+        let len = read_zigzag(reader)?;
+        if len == 0 {
+            break;
+        }
+        for _ in 0..len {
+            let key = read_avro_bytes(reader)?;
+            let value = read_avro_bytes(reader)?;
+            match key.as_slice() {
+                b"avro.schema" => {
+                    avro_schema = Some(serde_json::from_slice(&value).map_err(|e| {
+                        ArrowError::external("could not parse Avro schema as JSON", e)
+                    })?);
+                }
+                b"avro.codec" => {
+                    codec = match value.as_slice() {
+                        b"null" => Compression::Null,
+                        b"deflate" => Compression::Deflate,
+                        b"snappy" => Compression::Snappy,
+                        other => {
+                            return Err(ArrowError::nyi(format!(
+                                "unsupported Avro codec {}",
+                                String::from_utf8_lossy(other)
+                            )))
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut sync = [0u8; 16];
+    reader.read_exact(&mut sync)?;
+
+    let avro_schema: AvroSchema =
+        avro_schema.ok_or_else(|| ArrowError::oos("Avro file is missing its \"avro.schema\" metadata"))?;
+    let (_, schema) = schema::infer_schema(&avro_schema)?;
+
+    Ok((avro_schema, schema, codec, sync))
+}
+
+/// Iterates over the raw (still compressed) blocks of an Avro file, stopping when a sync marker
+/// is followed immediately by EOF.
+pub struct BlockStreamIterator<R: Read> {
+    reader: R,
+    file_marker: [u8; 16],
+}
+
+impl<R: Read> BlockStreamIterator<R> {
+    pub fn new(reader: R, file_marker: [u8; 16]) -> Self {
+        Self { reader, file_marker }
+    }
+}
+
+impl<R: Read> Iterator for BlockStreamIterator<R> {
+    /// `(number of rows, compressed bytes)`
+    type Item = Result<(usize, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rows = match read_zigzag(&mut self.reader) {
+            Ok(rows) => rows,
+            Err(_) => return None,
+        };
+        Some((|| {
+            let size = read_zigzag(&mut self.reader)? as usize;
+            let mut bytes = vec![0u8; size];
+            self.reader.read_exact(&mut bytes)?;
+
+            let mut marker = [0u8; 16];
+            self.reader.read_exact(&mut marker)?;
+            if marker != self.file_marker {
+                return Err(ArrowError::oos("Avro block sync marker does not match the file marker"));
+            }
+            Ok((rows as usize, bytes))
+        })())
+    }
+}
+
+/// Decompresses the blocks yielded by a [`BlockStreamIterator`] according to a [`Compression`].
+pub struct Decompressor<R: Read> {
+    blocks: BlockStreamIterator<R>,
+    compression: Compression,
+}
+
+impl<R: Read> Decompressor<R> {
+    pub fn new(blocks: BlockStreamIterator<R>, compression: Compression) -> Self {
+        Self { blocks, compression }
+    }
+}
+
+impl<R: Read> Iterator for Decompressor<R> {
+    /// `(number of rows, decompressed bytes)`
+    type Item = Result<(usize, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rows, bytes) = match self.blocks.next()? {
+            Ok(x) => x,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let decompressed = match self.compression {
+            Compression::Null => Ok(bytes),
+            Compression::Deflate => {
+                use std::io::Read as _;
+                let mut out = Vec::new();
+                flate2::read::DeflateDecoder::new(bytes.as_slice())
+                    .read_to_end(&mut out)
+                    .map(|_| out)
+                    .map_err(ArrowError::from)
+            }
+            Compression::Snappy => {
+                // the last 4 bytes are a CRC32 checksum of the uncompressed data, not part of
+                // the compressed payload.
+                let (payload, _checksum) = bytes.split_at(bytes.len().saturating_sub(4));
+                snap::raw::Decoder::new()
+                    .decompress_vec(payload)
+                    .map_err(|e| ArrowError::external("snappy decompression failed", e))
+            }
+        };
+        Some(decompressed.map(|bytes| (rows, bytes)))
+    }
+}
+
+/// Decodes decompressed Avro blocks into `Chunk<Arc<dyn Array>>`, one per block.
+pub struct Reader<R: Read> {
+    decompressor: Decompressor<R>,
+    avro_fields: Vec<AvroField>,
+    fields: Vec<crate::datatypes::Field>,
+}
+
+impl<R: Read> Reader<R> {
+    pub fn new(decompressor: Decompressor<R>, avro_schema: AvroSchema, fields: Vec<crate::datatypes::Field>) -> Self {
+        let (avro_fields, _) = schema::infer_schema(&avro_schema).unwrap();
+        Self {
+            decompressor,
+            avro_fields,
+            fields,
+        }
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rows, bytes) = match self.decompressor.next()? {
+            Ok(x) => x,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(deserialize::deserialize(&bytes, rows, &self.avro_fields, &self.fields))
+    }
+}
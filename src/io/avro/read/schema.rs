@@ -0,0 +1,206 @@
+use serde_json::Value;
+
+use crate::datatypes::{DataType, Field, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+
+/// A parsed Avro schema, kept around (as the raw JSON) so that [`super::deserialize`] can
+/// recover logical-type annotations that have no representation in the arrow [`Schema`] alone
+/// (e.g. a `Decimal`'s precision/scale, or whether a `fixed` is a `duration`).
+pub type AvroSchema = Value;
+
+/// The Avro-side physical representation that a field must be decoded from. This is richer
+/// than [`DataType`] because e.g. both `Decimal` and `Duration` are encoded as Avro `fixed`,
+/// distinguished only by their `logicalType`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhysicalType {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Date32,
+    TimeMillis,
+    TimeMicros,
+    TimestampMillis,
+    TimestampMicros,
+    /// `bytes` or `fixed(size)` carrying a big-endian two's-complement decimal.
+    Decimal { precision: usize, scale: usize, size: Option<usize> },
+    /// `fixed(12)`: little-endian months (u32), days (u32), milliseconds (u32).
+    Duration,
+    List(Box<AvroField>),
+    /// An Avro `enum`, read back as `Dictionary<i32, Utf8>`.
+    Enum(Vec<String>),
+}
+
+/// An Avro field paired with the [`PhysicalType`] it must be decoded as and its nullability.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvroField {
+    pub name: String,
+    pub physical_type: PhysicalType,
+    pub nullable: bool,
+}
+
+fn external(message: impl Into<String>) -> ArrowError {
+    ArrowError::nyi(message)
+}
+
+/// Infers the [`AvroField`] (physical decode plan) and corresponding arrow [`Field`] for a
+/// single entry of an Avro record's `"fields"` array.
+fn infer_field(field: &Value) -> Result<(AvroField, Field)> {
+    let name = field["name"]
+        .as_str()
+        .ok_or_else(|| external("Avro field must have a name"))?
+        .to_string();
+
+    let (inner, nullable) = match field["type"].as_array() {
+        // a ["null", T] union is how Avro encodes a nullable field
+        Some(options) if options.len() == 2 && options.contains(&Value::String("null".into())) => {
+            let inner = options
+                .iter()
+                .find(|x| x != &&Value::String("null".into()))
+                .unwrap();
+            (inner, true)
+        }
+        _ => (&field["type"], false),
+    };
+
+    // a field-level sibling `"logicalType"` (e.g. `{"name": "date", "type": "int",
+    // "logicalType": "date"}`) is equivalent to nesting it inside the type itself.
+    let owned;
+    let inner = match (inner.as_str(), field.get("logicalType")) {
+        (Some(base), Some(logical_type)) => {
+            let mut merged = serde_json::Map::new();
+            merged.insert("type".to_string(), Value::String(base.to_string()));
+            merged.insert("logicalType".to_string(), logical_type.clone());
+            if let Some(precision) = field.get("precision") {
+                merged.insert("precision".to_string(), precision.clone());
+            }
+            if let Some(scale) = field.get("scale") {
+                merged.insert("scale".to_string(), scale.clone());
+            }
+            owned = Value::Object(merged);
+            &owned
+        }
+        _ => inner,
+    };
+
+    let (physical_type, data_type) = infer_type(inner)?;
+
+    let avro_field = AvroField {
+        name: name.clone(),
+        physical_type,
+        nullable,
+    };
+    let field = Field::new(name, data_type, nullable);
+    Ok((avro_field, field))
+}
+
+fn infer_type(type_: &Value) -> Result<(PhysicalType, DataType)> {
+    // a bare primitive, e.g. "int"
+    if let Some(name) = type_.as_str() {
+        return Ok(match name {
+            "null" => (PhysicalType::Null, DataType::Null),
+            "boolean" => (PhysicalType::Boolean, DataType::Boolean),
+            "int" => (PhysicalType::Int, DataType::Int32),
+            "long" => (PhysicalType::Long, DataType::Int64),
+            "float" => (PhysicalType::Float, DataType::Float32),
+            "double" => (PhysicalType::Double, DataType::Float64),
+            "bytes" => (PhysicalType::Bytes, DataType::Binary),
+            "string" => (PhysicalType::String, DataType::Utf8),
+            other => return Err(external(format!("unsupported Avro type {other}"))),
+        });
+    }
+
+    let base = type_["type"]
+        .as_str()
+        .ok_or_else(|| external("Avro complex type is missing a \"type\""))?;
+    let logical = type_["logicalType"].as_str();
+
+    Ok(match (base, logical) {
+        ("int", Some("date")) => (PhysicalType::Date32, DataType::Date32),
+        ("int", Some("time-millis")) => (
+            PhysicalType::TimeMillis,
+            DataType::Time32(TimeUnit::Millisecond),
+        ),
+        ("long", Some("time-micros")) => (
+            PhysicalType::TimeMicros,
+            DataType::Time64(TimeUnit::Microsecond),
+        ),
+        ("long", Some("timestamp-millis")) => (
+            PhysicalType::TimestampMillis,
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+        ),
+        ("long", Some("timestamp-micros")) => (
+            PhysicalType::TimestampMicros,
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+        ),
+        ("bytes", Some("decimal")) | ("fixed", Some("decimal")) => {
+            let precision = type_["precision"]
+                .as_u64()
+                .ok_or_else(|| external("decimal logicalType requires \"precision\""))?
+                as usize;
+            let scale = type_["scale"].as_u64().unwrap_or(0) as usize;
+            let size = type_["size"].as_u64().map(|x| x as usize);
+            (
+                PhysicalType::Decimal { precision, scale, size },
+                DataType::Decimal(precision, scale),
+            )
+        }
+        ("fixed", Some("duration")) => {
+            let size = type_["size"].as_u64().unwrap_or(0);
+            if size != 12 {
+                return Err(external("Avro duration logicalType must be fixed(12)"));
+            }
+            (
+                PhysicalType::Duration,
+                DataType::Interval(crate::datatypes::IntervalUnit::MonthDayNano),
+            )
+        }
+        ("array", _) => {
+            let items = &type_["items"];
+            let (avro_field, field) = infer_field(&serde_json::json!({
+                "name": "item",
+                "type": items,
+            }))?;
+            (
+                PhysicalType::List(Box::new(avro_field)),
+                DataType::List(Box::new(field)),
+            )
+        }
+        ("enum", _) => {
+            let symbols = type_["symbols"]
+                .as_array()
+                .ok_or_else(|| external("Avro enum requires \"symbols\""))?
+                .iter()
+                .map(|x| x.as_str().unwrap_or_default().to_string())
+                .collect();
+            (
+                PhysicalType::Enum(symbols),
+                DataType::Dictionary(
+                    crate::datatypes::IntegerType::Int32,
+                    Box::new(DataType::Utf8),
+                    false,
+                ),
+            )
+        }
+        ("bytes", None) => (PhysicalType::Bytes, DataType::Binary),
+        ("string", None) => (PhysicalType::String, DataType::Utf8),
+        (other, _) => return Err(external(format!("unsupported Avro type {other}"))),
+    })
+}
+
+/// Infers an arrow [`Schema`] and the per-field Avro decode plan from a parsed Avro record
+/// schema.
+pub fn infer_schema(avro_schema: &AvroSchema) -> Result<(Vec<AvroField>, Schema)> {
+    let fields = avro_schema["fields"]
+        .as_array()
+        .ok_or_else(|| external("Avro schema must be a record with \"fields\""))?;
+
+    let (avro_fields, fields): (Vec<_>, Vec<_>) =
+        fields.iter().map(infer_field).collect::<Result<Vec<_>>>()?.into_iter().unzip();
+
+    Ok((avro_fields, Schema::from(fields)))
+}
@@ -0,0 +1,45 @@
+use std::io::Write;
+
+use crate::error::{ArrowError, Result};
+
+/// The block compression codecs supported by the writer, mirroring the set understood by
+/// [`super::super::read::Decompressor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Null,
+    Deflate,
+    Snappy,
+}
+
+impl Compression {
+    pub(super) fn as_str(&self) -> &'static str {
+        match self {
+            Compression::Null => "null",
+            Compression::Deflate => "deflate",
+            Compression::Snappy => "snappy",
+        }
+    }
+}
+
+/// Compresses `block` according to `compression`, returning the bytes to be written between a
+/// block's length prefix and its sync marker.
+pub fn compress(block: &[u8], compression: Compression) -> Result<Vec<u8>> {
+    Ok(match compression {
+        Compression::Null => block.to_vec(),
+        Compression::Deflate => {
+            let mut encoder =
+                flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(block)?;
+            encoder.finish()?
+        }
+        Compression::Snappy => {
+            // Avro's snappy framing appends a trailing CRC32 of the uncompressed data.
+            let mut compressed = snap::raw::Encoder::new()
+                .compress_vec(block)
+                .map_err(|e| ArrowError::external("snappy compression failed", e))?;
+            let checksum = crc32fast::hash(block);
+            compressed.extend_from_slice(&checksum.to_be_bytes());
+            compressed
+        }
+    })
+}
@@ -0,0 +1,134 @@
+//! APIs to write to Avro format.
+mod compress;
+mod schema;
+mod serialize;
+
+pub use compress::{compress, Compression};
+pub use schema::to_record;
+use serialize::write_record;
+
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::Result;
+
+const MAGIC: [u8; 4] = [b'O', b'b', b'j', 1];
+
+/// Writes the Avro file header (magic bytes, metadata map with the record schema and codec,
+/// and the sync marker) to `writer`, returning the sync marker so that it can be reused by
+/// [`write_block`].
+pub fn write_metadata<W: Write>(
+    writer: &mut W,
+    record: serde_json::Value,
+    compression: Compression,
+) -> Result<[u8; 16]> {
+    writer.write_all(&MAGIC)?;
+
+    // the metadata map has exactly two entries: the schema and the codec.
+    write_zigzag(writer, 2)?;
+    write_avro_bytes(writer, b"avro.schema")?;
+    write_avro_bytes(writer, record.to_string().as_bytes())?;
+    write_avro_bytes(writer, b"avro.codec")?;
+    write_avro_bytes(writer, compression.as_str().as_bytes())?;
+    // end of map
+    write_zigzag(writer, 0)?;
+
+    let sync: [u8; 16] = rand::random();
+    writer.write_all(&sync)?;
+    Ok(sync)
+}
+
+/// Writes a single block of `num_rows` already-encoded `records` to `writer`, compressing it
+/// with `compression` and terminating it with `sync`.
+pub fn write_block<W: Write>(
+    writer: &mut W,
+    records: &[u8],
+    num_rows: usize,
+    compression: Compression,
+    sync: &[u8; 16],
+) -> Result<()> {
+    let compressed = compress::compress(records, compression)?;
+
+    write_zigzag(writer, num_rows as i64)?;
+    write_zigzag(writer, compressed.len() as i64)?;
+    writer.write_all(&compressed)?;
+    writer.write_all(sync)?;
+    Ok(())
+}
+
+fn write_zigzag<W: Write>(writer: &mut W, value: i64) -> Result<()> {
+    let mut buffer = Vec::new();
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    loop {
+        if value & !0x7F == 0 {
+            buffer.push(value as u8);
+            break;
+        } else {
+            buffer.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+    }
+    writer.write_all(&buffer)?;
+    Ok(())
+}
+
+fn write_avro_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<()> {
+    write_zigzag(writer, bytes.len() as i64)?;
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+/// A high-level writer of Avro Object Container Files.
+///
+/// Each call to [`Writer::write`] appends a single Avro block containing the whole `Chunk`,
+/// mirroring how [`super::read::Reader`] yields one `Chunk` per block.
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: Schema,
+    compression: Compression,
+    sync: Option<[u8; 16]>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`] that will write batches conforming to `schema` to `writer`.
+    pub fn new(writer: W, schema: Schema, compression: Compression) -> Self {
+        Self {
+            writer,
+            schema,
+            compression,
+            sync: None,
+        }
+    }
+
+    /// Writes `chunk` as a single Avro block, writing the file header first if this is the
+    /// first call.
+    pub fn write(&mut self, chunk: &Chunk<Arc<dyn Array>>) -> Result<()> {
+        if self.sync.is_none() {
+            let record = to_record(&self.schema, chunk.arrays(), "root")?;
+            self.sync = Some(write_metadata(&mut self.writer, record, self.compression)?);
+        }
+        let sync = self.sync.unwrap();
+
+        let is_nullable = self
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.is_nullable)
+            .collect::<Vec<_>>();
+
+        let mut buffer = Vec::new();
+        for index in 0..chunk.len() {
+            write_record(chunk.arrays(), &is_nullable, index, &mut buffer)?;
+        }
+
+        write_block(&mut self.writer, &buffer, chunk.len(), self.compression, &sync)
+    }
+
+    /// Consumes this writer, returning the underlying `writer`.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use crate::array::{Array, DictionaryArray, Utf8Array};
+use crate::datatypes::{DataType, Field, IntervalUnit, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+
+/// Converts a [`Field`] into its Avro JSON schema representation.
+///
+/// Nullable fields are wrapped in a `["null", T]` union, matching the convention used by
+/// [`super::super::read`] when inferring arrow types from Avro schemas.
+fn field_to_json(field: &Field, array: &dyn Array) -> Result<Value> {
+    let inner = data_type_to_json(&field.data_type, array)?;
+    Ok(if field.is_nullable {
+        json!(["null", inner])
+    } else {
+        inner
+    })
+}
+
+/// The dictionary's values are used verbatim as the enum's `symbols`: every value present in
+/// the column must be written out once in the header, so a `Dictionary<_, Utf8>` column can
+/// only round-trip through Avro if its values array already contains the full symbol table.
+fn enum_symbols(array: &dyn Array) -> Result<Vec<String>> {
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .ok_or_else(|| ArrowError::nyi("Dictionary arrays must be keyed by i32 to write Avro"))?;
+
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .ok_or_else(|| ArrowError::nyi("only Dictionary<_, Utf8> can be written as an Avro enum"))?;
+
+    Ok(values.iter().map(|x| x.unwrap_or("").to_string()).collect())
+}
+
+fn data_type_to_json(data_type: &DataType, array: &dyn Array) -> Result<Value> {
+    Ok(match data_type {
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 => json!("int"),
+        DataType::Int64 => json!("long"),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 | DataType::LargeUtf8 => json!("string"),
+        DataType::Binary | DataType::LargeBinary => json!("bytes"),
+        DataType::Date32 => json!({
+            "type": "int",
+            "logicalType": "date",
+        }),
+        DataType::Time32(TimeUnit::Millisecond) => json!({
+            "type": "int",
+            "logicalType": "time-millis",
+        }),
+        DataType::Time64(TimeUnit::Microsecond) => json!({
+            "type": "long",
+            "logicalType": "time-micros",
+        }),
+        DataType::Timestamp(TimeUnit::Millisecond, None) => json!({
+            "type": "long",
+            "logicalType": "timestamp-millis",
+        }),
+        DataType::Timestamp(TimeUnit::Microsecond, None) => json!({
+            "type": "long",
+            "logicalType": "timestamp-micros",
+        }),
+        DataType::Decimal(precision, scale) => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::Interval(IntervalUnit::MonthDayNano) => json!({
+            "type": "fixed",
+            "name": "duration",
+            "size": 12,
+            "logicalType": "duration",
+        }),
+        DataType::List(inner) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<crate::array::ListArray<i32>>()
+                .ok_or_else(|| ArrowError::nyi("expected a ListArray"))?
+                .values();
+            json!({
+                "type": "array",
+                "items": field_to_json(inner, values.as_ref())?,
+            })
+        }
+        DataType::Dictionary(_, values, _) if values.as_ref() == &DataType::Utf8 => json!({
+            "type": "enum",
+            "name": "",
+            "symbols": enum_symbols(array)?,
+        }),
+        other => {
+            return Err(ArrowError::nyi(format!(
+                "writing DataType {other:?} to Avro is not supported"
+            )))
+        }
+    })
+}
+
+/// Derives an Avro record schema (as a JSON value, ready to be embedded in the file header)
+/// from an arrow [`Schema`] and a representative batch of `columns`.
+///
+/// A sample of the data is required (rather than the `Schema` alone) because `Dictionary<_,
+/// Utf8>` columns are written as Avro `enum`s, whose `symbols` must be known up front.
+pub fn to_record(schema: &Schema, columns: &[Arc<dyn Array>], name: &str) -> Result<Value> {
+    let fields = schema
+        .fields
+        .iter()
+        .zip(columns.iter())
+        .map(|(field, array)| {
+            Ok(json!({
+                "name": field.name,
+                "type": field_to_json(field, array.as_ref())?,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "type": "record",
+        "name": name,
+        "fields": fields,
+    }))
+}
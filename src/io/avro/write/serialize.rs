@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::{months_days_ns, NativeType};
+
+/// Encodes `value` as an Avro zig-zag variable-length `int`/`long`.
+fn zigzag_encode(value: i64, buffer: &mut Vec<u8>) {
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    loop {
+        if value & !0x7F == 0 {
+            buffer.push(value as u8);
+            break;
+        } else {
+            buffer.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+    }
+}
+
+fn write_bytes(bytes: &[u8], buffer: &mut Vec<u8>) {
+    zigzag_encode(bytes.len() as i64, buffer);
+    buffer.extend_from_slice(bytes);
+}
+
+/// Writes an Avro `fixed(size)` value: unlike `bytes`, its length is implied by the schema, so
+/// no length prefix is written.
+fn write_fixed(bytes: &[u8], buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(bytes);
+}
+
+/// Encodes an `i128` as the minimal big-endian two's-complement byte representation Avro's
+/// `decimal` logicalType expects, mirroring [`super::super::read::deserialize::decode_decimal_bytes`].
+fn encode_decimal_bytes(value: i128) -> Vec<u8> {
+    let mut bytes = value.to_be_bytes().to_vec();
+    while bytes.len() > 1
+        && ((bytes[0] == 0x00 && bytes[1] & 0x80 == 0) || (bytes[0] == 0xFF && bytes[1] & 0x80 != 0))
+    {
+        bytes.remove(0);
+    }
+    bytes
+}
+
+/// Encodes arrow's `months_days_ns` interval as a `fixed(12)` Avro `duration` (little-endian
+/// months, days, milliseconds), the inverse of `super::super::read::deserialize::decode_duration`.
+fn encode_duration(value: months_days_ns) -> [u8; 12] {
+    let mut bytes = [0u8; 12];
+    bytes[0..4].copy_from_slice(&(value.0 as u32).to_le_bytes());
+    bytes[4..8].copy_from_slice(&(value.1 as u32).to_le_bytes());
+    let millis = (value.2 / 1_000_000) as u32;
+    bytes[8..12].copy_from_slice(&millis.to_le_bytes());
+    bytes
+}
+
+/// Serializes the value at `index` of `array` into `buffer`, following Avro's binary encoding.
+///
+/// `array`'s nullability is encoded by the caller (as the union branch index), mirroring how
+/// [`super::super::read`] decodes the same unions on the way in.
+pub fn write_value(array: &dyn Array, index: usize, buffer: &mut Vec<u8>) -> Result<()> {
+    match array.data_type() {
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            buffer.push(array.value(index) as u8);
+        }
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            let value = primitive_value::<i32>(array, index);
+            zigzag_encode(value as i64, buffer);
+        }
+        DataType::Int64 | DataType::Time64(_) | DataType::Timestamp(_, _) => {
+            let value = primitive_value::<i64>(array, index);
+            zigzag_encode(value, buffer);
+        }
+        DataType::Decimal(_, _) => {
+            let value = primitive_value::<i128>(array, index);
+            write_bytes(&encode_decimal_bytes(value), buffer);
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let value = primitive_value::<months_days_ns>(array, index);
+            write_fixed(&encode_duration(value), buffer);
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 => {
+            let value = primitive_value::<u32>(array, index);
+            zigzag_encode(value as i64, buffer);
+        }
+        DataType::Float32 => {
+            let value = primitive_value::<f32>(array, index);
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        DataType::Float64 => {
+            let value = primitive_value::<f64>(array, index);
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            write_bytes(array.value(index).as_bytes(), buffer);
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            write_bytes(array.value(index).as_bytes(), buffer);
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            write_bytes(array.value(index), buffer);
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            write_bytes(array.value(index), buffer);
+        }
+        DataType::List(inner) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = array.offsets();
+            let start = offsets[index].to_usize();
+            let end = offsets[index + 1].to_usize();
+            let values = array.values();
+
+            // a single block followed by the zero-length terminator block
+            zigzag_encode((end - start) as i64, buffer);
+            for i in start..end {
+                if inner.is_nullable {
+                    write_nullable_value(values.as_ref(), i, buffer)?;
+                } else {
+                    write_value(values.as_ref(), i, buffer)?;
+                }
+            }
+            zigzag_encode(0, buffer);
+        }
+        DataType::Dictionary(_, _, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<i32>>()
+                .unwrap();
+            zigzag_encode(array.keys().value(index) as i64, buffer);
+        }
+        other => {
+            return Err(ArrowError::nyi(format!(
+                "writing DataType {other:?} to Avro is not supported"
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Serializes the value at `index` of a nullable `array`, prefixing it with the union branch
+/// (`0` for `null`, `1` for the value), as required for Avro `["null", T]` unions.
+pub fn write_nullable_value(array: &dyn Array, index: usize, buffer: &mut Vec<u8>) -> Result<()> {
+    match array.validity() {
+        Some(validity) if !validity.get_bit(index) => {
+            zigzag_encode(0, buffer);
+        }
+        _ => {
+            zigzag_encode(1, buffer);
+            write_value(array, index, buffer)?;
+        }
+    }
+    Ok(())
+}
+
+fn primitive_value<T: NativeType>(array: &dyn Array, index: usize) -> T {
+    array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .value(index)
+}
+
+/// Serializes row `index` of `columns` as a single Avro record, appending it to `buffer`.
+pub fn write_record(
+    columns: &[Arc<dyn Array>],
+    is_nullable: &[bool],
+    index: usize,
+    buffer: &mut Vec<u8>,
+) -> Result<()> {
+    for (array, nullable) in columns.iter().zip(is_nullable.iter()) {
+        if *nullable {
+            write_nullable_value(array.as_ref(), index, buffer)?;
+        } else {
+            write_value(array.as_ref(), index, buffer)?;
+        }
+    }
+    Ok(())
+}
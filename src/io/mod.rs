@@ -0,0 +1,3 @@
+//! APIs to read from and write to other formats.
+pub mod avro;
+pub mod parquet;
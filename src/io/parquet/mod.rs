@@ -0,0 +1,2 @@
+//! APIs to read from and write to Parquet format.
+pub mod read;
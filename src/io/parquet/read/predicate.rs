@@ -0,0 +1,219 @@
+use parquet2::indexes::{ByteIndex, Index, NativeIndex};
+use parquet2::statistics::{BinaryStatistics, PrimitiveStatistics, Statistics};
+
+/// A literal value a [`ColumnPredicate`] can be compared against. Kept deliberately small: it
+/// covers the physical types whose Parquet statistics are simple to compare byte-for-byte or
+/// numerically, which is enough to prune on the common cases (ids, timestamps, prices, ...).
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Literal {
+    Int64(i64),
+    Float64(f64),
+    String(String),
+}
+
+/// The statistics a row group (or, within it, a data page) carries for a single column chunk.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnStatistics {
+    pub min: Option<Literal>,
+    pub max: Option<Literal>,
+    pub null_count: Option<u64>,
+    pub row_count: u64,
+}
+
+/// A predicate pushed down to the Parquet reader for a single column, evaluated against each
+/// row group's (and, where available, each page's) statistics.
+#[derive(Debug, Clone)]
+pub enum ColumnPredicate {
+    /// Keep only rows whose value in `column` could fall within `[min, max]` (either bound may
+    /// be omitted for an open range).
+    InRange {
+        column: usize,
+        min: Option<Literal>,
+        max: Option<Literal>,
+    },
+    /// Keep only rows where `column` is not null.
+    NotNull { column: usize },
+}
+
+impl ColumnPredicate {
+    pub(super) fn column(&self) -> usize {
+        match self {
+            ColumnPredicate::InRange { column, .. } => *column,
+            ColumnPredicate::NotNull { column } => *column,
+        }
+    }
+
+    /// Whether a chunk described by `statistics` could possibly satisfy this predicate.
+    ///
+    /// Returns `true` (never prune) whenever the statistics needed to decide are missing, which
+    /// is both the safe default and how the reader falls back to reading everything when a file
+    /// has no statistics.
+    fn can_be_satisfied(&self, statistics: &ColumnStatistics) -> bool {
+        match self {
+            ColumnPredicate::InRange { min, max, .. } => {
+                let min_ok = match (min, &statistics.max) {
+                    (Some(predicate_min), Some(stats_max)) => {
+                        partial_cmp_or_true(predicate_min, stats_max, |o| o != std::cmp::Ordering::Greater)
+                    }
+                    _ => true,
+                };
+                let max_ok = match (max, &statistics.min) {
+                    (Some(predicate_max), Some(stats_min)) => {
+                        partial_cmp_or_true(predicate_max, stats_min, |o| o != std::cmp::Ordering::Less)
+                    }
+                    _ => true,
+                };
+                min_ok && max_ok
+            }
+            ColumnPredicate::NotNull { .. } => {
+                // prune only if we are certain every row is null
+                statistics.null_count.map_or(true, |nulls| nulls < statistics.row_count)
+            }
+        }
+    }
+}
+
+/// Compares `a` against `b`, defaulting to "cannot prune" (`true`) when they are not
+/// comparable (e.g. a `Literal::Int64` predicate against a `Literal::String` statistic, which
+/// should never happen for a well-formed schema but must not cause an incorrect prune).
+fn partial_cmp_or_true(a: &Literal, b: &Literal, f: impl Fn(std::cmp::Ordering) -> bool) -> bool {
+    // the derived `PartialOrd` orders different variants by declaration order, which says
+    // nothing about the values themselves.
+    if std::mem::discriminant(a) != std::mem::discriminant(b) {
+        return true;
+    }
+    a.partial_cmp(b).map_or(true, f)
+}
+
+/// How many of a scan's row groups, and (within the survivors, where column/offset indexes are
+/// present) data pages, were skipped versus read, so that callers can verify predicate pushdown
+/// is actually having an effect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruningStatistics {
+    pub row_groups_skipped: usize,
+    pub row_groups_read: usize,
+    pub pages_skipped: usize,
+    pub pages_read: usize,
+}
+
+/// Decides whether a row group (or page) can be skipped entirely, given its per-column
+/// `statistics` (indexed the same way as `predicates`' `column`). A row group with no statistics
+/// for a predicate's column is never pruned.
+pub fn can_skip(predicates: &[ColumnPredicate], statistics: &[Option<ColumnStatistics>]) -> bool {
+    predicates.iter().any(|predicate| {
+        statistics
+            .get(predicate.column())
+            .and_then(|x| x.as_ref())
+            .map_or(false, |stats| !predicate.can_be_satisfied(stats))
+    })
+}
+
+/// Downcasts a `parquet2` statistics object to the concrete physical type we know how to compare,
+/// returning its `(min, max)` as [`Literal`]s. Physical types we don't compare (e.g. nested or
+/// boolean columns) yield `None`, leaving those columns unprunable rather than wrongly pruned.
+pub(super) fn literal_bounds(statistics: &dyn Statistics) -> Option<(Option<Literal>, Option<Literal>)> {
+    if let Some(s) = statistics.as_any().downcast_ref::<PrimitiveStatistics<i64>>() {
+        Some((s.min_value.map(Literal::Int64), s.max_value.map(Literal::Int64)))
+    } else if let Some(s) = statistics.as_any().downcast_ref::<PrimitiveStatistics<i32>>() {
+        Some((
+            s.min_value.map(|v| Literal::Int64(v as i64)),
+            s.max_value.map(|v| Literal::Int64(v as i64)),
+        ))
+    } else if let Some(s) = statistics.as_any().downcast_ref::<PrimitiveStatistics<f64>>() {
+        Some((s.min_value.map(Literal::Float64), s.max_value.map(Literal::Float64)))
+    } else if let Some(s) = statistics.as_any().downcast_ref::<BinaryStatistics>() {
+        let to_string = |bytes: &Vec<u8>| String::from_utf8_lossy(bytes).into_owned();
+        Some((
+            s.min_value.as_ref().map(to_string).map(Literal::String),
+            s.max_value.as_ref().map(to_string).map(Literal::String),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Downcasts a `parquet2` column index to the concrete physical type we know how to compare,
+/// returning page `page`'s `(min, max)` as [`Literal`]s and its null count. Mirrors
+/// [`literal_bounds`], but reads one page's entry out of the index's per-page vectors instead of
+/// a row group's single summary statistics.
+pub(super) fn page_bounds_and_nulls(
+    index: &dyn Index,
+    page: usize,
+) -> Option<(Option<Literal>, Option<Literal>, Option<u64>)> {
+    let null_count = |count: Option<i64>| count.map(|n| n.max(0) as u64);
+
+    if let Some(i) = index.as_any().downcast_ref::<NativeIndex<i64>>() {
+        let p = i.indexes.get(page)?;
+        Some((p.min.map(Literal::Int64), p.max.map(Literal::Int64), null_count(p.null_count)))
+    } else if let Some(i) = index.as_any().downcast_ref::<NativeIndex<i32>>() {
+        let p = i.indexes.get(page)?;
+        Some((
+            p.min.map(|v| Literal::Int64(v as i64)),
+            p.max.map(|v| Literal::Int64(v as i64)),
+            null_count(p.null_count),
+        ))
+    } else if let Some(i) = index.as_any().downcast_ref::<NativeIndex<f64>>() {
+        let p = i.indexes.get(page)?;
+        Some((p.min.map(Literal::Float64), p.max.map(Literal::Float64), null_count(p.null_count)))
+    } else if let Some(i) = index.as_any().downcast_ref::<ByteIndex>() {
+        let p = i.indexes.get(page)?;
+        let to_string = |bytes: &Vec<u8>| String::from_utf8_lossy(bytes).into_owned();
+        Some((
+            p.min.as_ref().map(to_string).map(Literal::String),
+            p.max.as_ref().map(to_string).map(Literal::String),
+            null_count(p.null_count),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Filters `units` (one `Vec<Option<ColumnStatistics>>` per row group or page, indexed by
+/// column) down to the indices of the units that survive `predicates`, incrementing `read`/
+/// `skipped` for each kept/pruned unit.
+fn prune(
+    predicates: &[ColumnPredicate],
+    units: &[Vec<Option<ColumnStatistics>>],
+    read: &mut usize,
+    skipped: &mut usize,
+) -> Vec<usize> {
+    units
+        .iter()
+        .enumerate()
+        .filter_map(|(index, statistics)| {
+            if predicates.is_empty() || !can_skip(predicates, statistics) {
+                *read += 1;
+                Some(index)
+            } else {
+                *skipped += 1;
+                None
+            }
+        })
+        .collect()
+}
+
+/// Filters `groups` (one `Vec<Option<ColumnStatistics>>` per row group, indexed by column) down
+/// to the indices of the row groups that survive `predicates`, accumulating row-group-level
+/// skip/read counts into `stats`.
+pub fn prune_row_groups(
+    predicates: &[ColumnPredicate],
+    groups: &[Vec<Option<ColumnStatistics>>],
+    stats: &mut PruningStatistics,
+) -> Vec<usize> {
+    prune(predicates, groups, &mut stats.row_groups_read, &mut stats.row_groups_skipped)
+}
+
+/// Filters `pages` (one `Vec<Option<ColumnStatistics>>` per data page, indexed by column) down to
+/// the indices of the pages that survive `predicates`, accumulating page-level skip/read counts
+/// into `stats`.
+///
+/// Used only within a row group that already survived [`prune_row_groups`], and only for columns
+/// whose file carries column/offset indexes; a row group with no indexes at all skips this step
+/// entirely and reads every page, exactly as it did before page pruning existed.
+pub fn prune_pages(
+    predicates: &[ColumnPredicate],
+    pages: &[Vec<Option<ColumnStatistics>>],
+    stats: &mut PruningStatistics,
+) -> Vec<usize> {
+    prune(predicates, pages, &mut stats.pages_read, &mut stats.pages_skipped)
+}
@@ -0,0 +1,85 @@
+use arrow2::array::{Array, MutableBinaryViewArray, MutableUtf8ViewArray};
+
+// `new_null_array`/`new_empty_array`/`clone` (tested generically in `tests/it/array/mod.rs` for
+// every other `DataType`) dispatch through a part of `array::mod` not present in this checkout,
+// so `Utf8View`/`BinaryView` are exercised directly against their own constructors here instead.
+#[test]
+fn null_array_has_len_and_all_null() {
+    let mut array = MutableUtf8ViewArray::new();
+    for _ in 0..10 {
+        array.push_null();
+    }
+    let array = array.into_arc();
+
+    assert_eq!(array.len(), 10);
+    assert_eq!(array.null_count(), 10);
+}
+
+#[test]
+fn empty_array_has_no_values() {
+    let array = MutableBinaryViewArray::new().into_arc();
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn clone_produces_an_equal_array() {
+    let mut array = MutableUtf8ViewArray::new();
+    array.push_null();
+    array.push(Some("a string longer than twelve bytes"));
+    let array = array
+        .into_arc()
+        .as_any()
+        .downcast_ref::<arrow2::array::Utf8ViewArray>()
+        .unwrap()
+        .clone();
+
+    assert_eq!(array.clone(), array);
+}
+
+#[test]
+fn inline_and_spilled_values() {
+    let mut array = MutableUtf8ViewArray::new();
+    array.push(Some("short")); // inlined: <= 12 bytes
+    array.push_null();
+    array.push(Some("a string longer than twelve bytes")); // spills to a data buffer
+    let array = array.into_arc();
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 1);
+}
+
+#[test]
+fn slicing_is_cheap() {
+    let mut array = MutableBinaryViewArray::new();
+    for i in 0..100 {
+        array.push_value(format!("value number {i}, long enough to spill").as_bytes());
+    }
+    let array = array.into_arc();
+    let array = array.as_any().downcast_ref::<arrow2::array::BinaryViewArray>();
+    let array = array.unwrap();
+
+    let sliced = array.slice(10, 5);
+    let sliced = sliced
+        .as_any()
+        .downcast_ref::<arrow2::array::BinaryViewArray>()
+        .unwrap();
+
+    assert_eq!(sliced.len(), 5);
+    assert_eq!(sliced.value(0), array.value(10));
+    // the slice shares the original data buffer instead of copying its bytes
+    assert_eq!(sliced.data_buffers()[0].as_ptr(), array.data_buffers()[0].as_ptr());
+}
+
+#[test]
+fn utf8_view_rejects_invalid_utf8() {
+    use arrow2::array::Utf8ViewArray;
+    use arrow2::datatypes::DataType;
+
+    let invalid = vec![0x80u8, 0x81];
+    let mut views = vec![arrow2::array::View::new_inline(&invalid).0];
+    // a second, empty (and valid UTF-8) inlined value alongside the failing one.
+    views.push(arrow2::array::View::default().0);
+
+    let result = Utf8ViewArray::try_new(DataType::Utf8View, views.into(), vec![], None);
+    assert!(result.is_err());
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use arrow2::array::{
+    growable::capacities, growable::Capacities, growable::Growable, growable::GrowablePrimitive,
+    Array, FixedSizeListArray, Int32Array, StructArray,
+};
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn flat_capacity_sums_inputs() {
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let b = Int32Array::from_slice([4, 5]);
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+
+    let capacity = capacities(&arrays);
+    assert_eq!(capacity.len(), 5);
+    assert!(!capacity.is_empty());
+    assert!(matches!(capacity, Capacities::Array(5)));
+}
+
+#[test]
+fn empty_capacity_is_empty() {
+    let arrays: Vec<&dyn Array> = vec![];
+    assert!(capacities(&arrays).is_empty());
+}
+
+#[test]
+fn fixed_size_list_capacity_recurses_into_child() {
+    let field = Field::new("item", DataType::Int32, false);
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]);
+    let a = FixedSizeListArray::new(
+        DataType::FixedSizeList(Box::new(field.clone()), 3),
+        std::sync::Arc::new(values),
+        None,
+    );
+    let b_values = Int32Array::from_slice([7, 8, 9]);
+    let b = FixedSizeListArray::new(
+        DataType::FixedSizeList(Box::new(field), 3),
+        std::sync::Arc::new(b_values),
+        None,
+    );
+
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+    let capacity = capacities(&arrays);
+
+    // 2 rows from `a` + 1 row from `b`
+    assert_eq!(capacity.len(), 3);
+    // 3 values per row, summed across both inputs: 6 + 3
+    match capacity {
+        Capacities::List(len, Some(child)) => {
+            assert_eq!(len, 3);
+            assert_eq!(child.len(), 9);
+        }
+        other => panic!("expected a nested List capacity, got {other:?}"),
+    }
+}
+
+#[test]
+fn struct_capacity_recurses_into_each_field() {
+    let a_field = Field::new("a", DataType::Int32, false);
+    let b_field = Field::new("b", DataType::Int32, false);
+    let data_type = DataType::Struct(vec![a_field, b_field]);
+
+    let a = StructArray::new(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2])),
+            Arc::new(Int32Array::from_slice([3, 4])),
+        ],
+        None,
+    );
+    let b = StructArray::new(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from_slice([5])),
+            Arc::new(Int32Array::from_slice([6])),
+        ],
+        None,
+    );
+
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+    let capacity = capacities(&arrays);
+
+    match capacity {
+        Capacities::Struct(len, children) => {
+            assert_eq!(len, 3);
+            assert_eq!(children.len(), 2);
+            assert!(children.iter().all(|child| child.len() == 3));
+        }
+        other => panic!("expected a Struct capacity, got {other:?}"),
+    }
+}
+
+#[test]
+fn growable_primitive_consumes_capacity_hint() {
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let b = Int32Array::from_slice([4, 5]);
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+    let capacity = capacities(&arrays);
+
+    let mut growable = GrowablePrimitive::new(vec![&a, &b], capacity);
+    growable.extend(0, 1, 2);
+    growable.extend(1, 0, 1);
+    let result = growable.as_arc();
+    let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+
+    assert_eq!(result, &Int32Array::from_slice([2, 3, 4]));
+}
@@ -1,4 +1,5 @@
 mod binary;
+mod binview;
 mod boolean;
 mod dictionary;
 mod equal;
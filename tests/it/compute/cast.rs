@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use arrow2::array::{Array, Int32Array, Int64Array, ListArray, StructArray};
+use arrow2::compute::cast::{cast, cast_rename, is_rename_only};
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn list_item_rename_is_rename_only() {
+    let from = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let to = DataType::List(Box::new(Field::new("element", DataType::Int32, true)));
+
+    assert!(is_rename_only(&from, &to));
+    // a child *type* difference is not a rename
+    let different_type = DataType::List(Box::new(Field::new("element", DataType::Int64, true)));
+    assert!(!is_rename_only(&from, &different_type));
+    // a child *nullability* difference is not a rename either: reusing `from`'s buffers under
+    // `to`'s (different) nullability would make its validity meaningless.
+    let different_nullability =
+        DataType::List(Box::new(Field::new("element", DataType::Int32, false)));
+    assert!(!is_rename_only(&from, &different_nullability));
+}
+
+#[test]
+fn cast_rename_reuses_buffers() {
+    let values = Int32Array::from_slice([1, 2, 3, 4]);
+    let from = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let array = ListArray::<i32>::new(
+        from.clone(),
+        vec![0, 2, 4].try_into().unwrap(),
+        Arc::new(values),
+        None,
+    );
+
+    let to = DataType::List(Box::new(Field::new("element", DataType::Int32, true)));
+    let result = cast_rename(&array, &to).unwrap();
+
+    assert_eq!(result.data_type(), &to);
+    assert_eq!(result.len(), array.len());
+}
+
+#[test]
+fn struct_field_rename() {
+    let a = Int32Array::from_slice([1, 2]);
+    let from = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+    let array = StructArray::new(from, vec![Arc::new(a)], None);
+
+    let to = DataType::Struct(vec![Field::new("renamed", DataType::Int32, false)]);
+    assert!(is_rename_only(array.data_type(), &to));
+
+    let result = cast_rename(&array, &to).unwrap();
+    assert_eq!(result.data_type(), &to);
+}
+
+#[test]
+fn cast_dispatches_rename_only_casts_to_cast_rename() {
+    let a = Int32Array::from_slice([1, 2]);
+    let from = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+    let array = StructArray::new(from, vec![Arc::new(a)], None);
+
+    let to = DataType::Struct(vec![Field::new("renamed", DataType::Int32, false)]);
+    let result = cast(&array, &to).unwrap();
+
+    assert_eq!(result.data_type(), &to);
+}
+
+#[test]
+fn cast_falls_through_to_normal_casting_for_type_differing_casts() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let result = cast(&array, &DataType::Int64).unwrap();
+
+    assert_eq!(
+        result.as_any().downcast_ref::<Int64Array>().unwrap(),
+        &Int64Array::from_slice([1i64, 2, 3]),
+    );
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use arrow2::array::{Array, FixedSizeListArray, Int32Array, StructArray};
+use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn concatenate_primitives() {
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let b = Int32Array::from(vec![Some(4), None]);
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+
+    let result = concatenate(&arrays).unwrap();
+    let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+
+    assert_eq!(result, &Int32Array::from(vec![Some(1), Some(2), Some(3), Some(4), None]));
+}
+
+#[test]
+fn concatenate_fixed_size_lists() {
+    let field = Field::new("item", DataType::Int32, false);
+    let data_type = DataType::FixedSizeList(Box::new(field), 2);
+
+    let a = FixedSizeListArray::new(
+        data_type.clone(),
+        Arc::new(Int32Array::from_slice([1, 2, 3, 4])),
+        None,
+    );
+    let b = FixedSizeListArray::new(data_type, Arc::new(Int32Array::from_slice([5, 6])), None);
+
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+    let result = concatenate(&arrays).unwrap();
+    let result = result.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(
+        result.values().as_any().downcast_ref::<Int32Array>().unwrap(),
+        &Int32Array::from_slice([1, 2, 3, 4, 5, 6]),
+    );
+}
+
+#[test]
+fn concatenate_structs() {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+
+    let a = StructArray::new(
+        data_type.clone(),
+        vec![Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>],
+        None,
+    );
+    let b = StructArray::new(
+        data_type,
+        vec![Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>],
+        None,
+    );
+
+    let arrays: Vec<&dyn Array> = vec![&a, &b];
+    let result = concatenate(&arrays).unwrap();
+    let result = result.as_any().downcast_ref::<StructArray>().unwrap();
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(
+        result.values()[0].as_any().downcast_ref::<Int32Array>().unwrap(),
+        &Int32Array::from_slice([1, 2, 3]),
+    );
+}
+
+#[test]
+fn concatenate_requires_at_least_one_array() {
+    let arrays: Vec<&dyn Array> = vec![];
+    assert!(concatenate(&arrays).is_err());
+}
@@ -0,0 +1,3 @@
+mod cast;
+mod concatenate;
+mod lower;
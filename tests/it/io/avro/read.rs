@@ -9,6 +9,7 @@ use arrow2::array::*;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
 use arrow2::io::avro::read;
+use arrow2::types::months_days_ns;
 
 pub(super) fn schema() -> (AvroSchema, Schema) {
     let raw_schema = r#"
@@ -40,7 +41,23 @@ pub(super) fn schema() -> (AvroSchema, Schema) {
                 "type": "enum",
                 "name": "",
                 "symbols" : ["SPADES", "HEARTS", "DIAMONDS", "CLUBS"]
-            }}
+            }},
+            {"name": "duration", "type": {
+                "type": "fixed",
+                "name": "duration",
+                "size": 12,
+                "logicalType": "duration"
+            }},
+            {"name": "decimal", "type": {
+                "type": "bytes",
+                "logicalType": "decimal",
+                "precision": 4,
+                "scale": 2
+            }},
+            {"name": "time_millis", "type": "int", "logicalType": "time-millis"},
+            {"name": "time_micros", "type": "long", "logicalType": "time-micros"},
+            {"name": "ts_millis", "type": "long", "logicalType": "timestamp-millis"},
+            {"name": "ts_micros", "type": "long", "logicalType": "timestamp-micros"}
         ]
     }
 "#;
@@ -64,6 +81,24 @@ pub(super) fn schema() -> (AvroSchema, Schema) {
             DataType::Dictionary(i32::KEY_TYPE, Box::new(DataType::Utf8), false),
             false,
         ),
+        Field::new(
+            "duration",
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            false,
+        ),
+        Field::new("decimal", DataType::Decimal(4, 2), false),
+        Field::new("time_millis", DataType::Time32(TimeUnit::Millisecond), false),
+        Field::new("time_micros", DataType::Time64(TimeUnit::Microsecond), false),
+        Field::new(
+            "ts_millis",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            false,
+        ),
+        Field::new(
+            "ts_micros",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
     ]);
 
     (AvroSchema::parse_str(raw_schema).unwrap(), schema)
@@ -92,6 +127,19 @@ pub(super) fn data() -> Chunk<Arc<dyn Array>> {
             Int32Array::from_slice([1, 0]),
             Arc::new(Utf8Array::<i32>::from_slice(["SPADES", "HEARTS"])),
         )),
+        Arc::new(PrimitiveArray::<months_days_ns>::from_slice([
+            months_days_ns::new(1, 1, 1_000_000),
+            months_days_ns::new(1, 2, 2_000_000),
+        ])),
+        Arc::new(PrimitiveArray::<i128>::from_slice([123i128, 200i128]).to(DataType::Decimal(4, 2))),
+        Arc::new(Int32Array::from_slice([1, 2]).to(DataType::Time32(TimeUnit::Millisecond))),
+        Arc::new(Int64Array::from_slice([1, 2]).to(DataType::Time64(TimeUnit::Microsecond))),
+        Arc::new(
+            Int64Array::from_slice([1, 2]).to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+        ),
+        Arc::new(
+            Int64Array::from_slice([1, 2]).to(DataType::Timestamp(TimeUnit::Microsecond, None)),
+        ),
     ];
 
     Chunk::try_new(columns).unwrap()
@@ -125,6 +173,11 @@ pub(super) fn write_avro(codec: Codec) -> std::result::Result<Vec<u8>, avro_rs::
         "duration",
         Value::Duration(Duration::new(Months::new(1), Days::new(1), Millis::new(1))),
     );
+    record.put("decimal", Value::Decimal(avro_rs::Decimal::from(vec![0x00, 0x7B])));
+    record.put("time_millis", Value::TimeMillis(1));
+    record.put("time_micros", Value::TimeMicros(1));
+    record.put("ts_millis", Value::TimestampMillis(1));
+    record.put("ts_micros", Value::TimestampMicros(1));
     writer.append(record)?;
 
     let mut record = Record::new(writer.schema()).unwrap();
@@ -145,6 +198,15 @@ pub(super) fn write_avro(codec: Codec) -> std::result::Result<Vec<u8>, avro_rs::
         ]),
     );
     record.put("enum", Value::Enum(0, "SPADES".to_string()));
+    record.put(
+        "duration",
+        Value::Duration(Duration::new(Months::new(1), Days::new(2), Millis::new(2))),
+    );
+    record.put("decimal", Value::Decimal(avro_rs::Decimal::from(vec![0x00, 0xC8])));
+    record.put("time_millis", Value::TimeMillis(2));
+    record.put("time_micros", Value::TimeMicros(2));
+    record.put("ts_millis", Value::TimestampMillis(2));
+    record.put("ts_micros", Value::TimestampMicros(2));
     writer.append(record)?;
     Ok(writer.into_inner().unwrap())
 }
@@ -0,0 +1,44 @@
+use arrow2::error::Result;
+use arrow2::io::avro::read;
+use arrow2::io::avro::write;
+
+use super::read::{data, schema};
+
+fn test(compression: write::Compression) -> Result<()> {
+    let (_, arrow_schema) = schema();
+    let expected = data();
+
+    let mut writer = write::Writer::new(Vec::<u8>::new(), arrow_schema.clone(), compression);
+    writer.write(&expected)?;
+    let avro = writer.into_inner();
+
+    let mut avro = avro.as_slice();
+    let (avro_schema, result_schema, codec, file_marker) = read::read_metadata(&mut avro)?;
+
+    let mut reader = read::Reader::new(
+        read::Decompressor::new(read::BlockStreamIterator::new(avro, file_marker), codec),
+        avro_schema,
+        result_schema.fields.clone(),
+    );
+
+    let result = reader.next().unwrap()?;
+
+    assert_eq!(result_schema, arrow_schema);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn write_without_codec() -> Result<()> {
+    test(write::Compression::Null)
+}
+
+#[test]
+fn write_deflate() -> Result<()> {
+    test(write::Compression::Deflate)
+}
+
+#[test]
+fn write_snappy() -> Result<()> {
+    test(write::Compression::Snappy)
+}
@@ -0,0 +1,103 @@
+use arrow2::io::parquet::read::{
+    can_skip, prune_pages, prune_row_groups, ColumnPredicate, ColumnStatistics, Literal, PruningStatistics,
+};
+
+fn stats(min: i64, max: i64, null_count: u64, row_count: u64) -> Option<ColumnStatistics> {
+    Some(ColumnStatistics {
+        min: Some(Literal::Int64(min)),
+        max: Some(Literal::Int64(max)),
+        null_count: Some(null_count),
+        row_count,
+    })
+}
+
+#[test]
+fn in_range_prunes_non_overlapping_row_group() {
+    let predicates = vec![ColumnPredicate::InRange {
+        column: 0,
+        min: Some(Literal::Int64(100)),
+        max: Some(Literal::Int64(200)),
+    }];
+
+    // row group's [0, 50] range never overlaps the predicate's [100, 200]
+    assert!(can_skip(&predicates, &[stats(0, 50, 0, 10)]));
+    // row group's [150, 250] range overlaps
+    assert!(!can_skip(&predicates, &[stats(150, 250, 0, 10)]));
+}
+
+#[test]
+fn missing_statistics_are_never_pruned() {
+    let predicates = vec![ColumnPredicate::InRange {
+        column: 0,
+        min: Some(Literal::Int64(100)),
+        max: Some(Literal::Int64(200)),
+    }];
+
+    assert!(!can_skip(&predicates, &[None]));
+}
+
+#[test]
+fn not_null_prunes_only_all_null_row_group() {
+    let predicates = vec![ColumnPredicate::NotNull { column: 0 }];
+
+    assert!(can_skip(&predicates, &[stats(0, 0, 10, 10)]));
+    assert!(!can_skip(&predicates, &[stats(0, 0, 9, 10)]));
+}
+
+#[test]
+fn prune_row_groups_keeps_everything_with_no_predicates() {
+    let groups = vec![vec![stats(0, 50, 0, 10)], vec![stats(150, 250, 0, 10)]];
+    let mut pruning_statistics = PruningStatistics::default();
+
+    let kept = prune_row_groups(&[], &groups, &mut pruning_statistics);
+
+    assert_eq!(kept, vec![0, 1]);
+    assert_eq!(pruning_statistics.row_groups_read, 2);
+    assert_eq!(pruning_statistics.row_groups_skipped, 0);
+}
+
+#[test]
+fn prune_row_groups_skips_non_matching_groups_and_tracks_counts() {
+    let predicates = vec![ColumnPredicate::InRange {
+        column: 0,
+        min: Some(Literal::Int64(100)),
+        max: Some(Literal::Int64(200)),
+    }];
+    let groups = vec![
+        vec![stats(0, 50, 0, 10)],
+        vec![stats(150, 250, 0, 10)],
+        vec![None],
+    ];
+    let mut pruning_statistics = PruningStatistics::default();
+
+    let kept = prune_row_groups(&predicates, &groups, &mut pruning_statistics);
+
+    assert_eq!(kept, vec![1, 2]);
+    assert_eq!(pruning_statistics.row_groups_read, 2);
+    assert_eq!(pruning_statistics.row_groups_skipped, 1);
+}
+
+#[test]
+fn prune_pages_skips_non_matching_pages_and_tracks_counts() {
+    let predicates = vec![ColumnPredicate::InRange {
+        column: 0,
+        min: Some(Literal::Int64(100)),
+        max: Some(Literal::Int64(200)),
+    }];
+    // same shape as `prune_row_groups`' test, but these are a row group's individual pages
+    let pages = vec![
+        vec![stats(0, 50, 0, 10)],
+        vec![stats(150, 250, 0, 10)],
+        vec![None],
+    ];
+    let mut pruning_statistics = PruningStatistics::default();
+
+    let kept = prune_pages(&predicates, &pages, &mut pruning_statistics);
+
+    assert_eq!(kept, vec![1, 2]);
+    assert_eq!(pruning_statistics.pages_read, 2);
+    assert_eq!(pruning_statistics.pages_skipped, 1);
+    // page-level counters are tracked independently of row-group-level ones
+    assert_eq!(pruning_statistics.row_groups_read, 0);
+    assert_eq!(pruning_statistics.row_groups_skipped, 0);
+}